@@ -0,0 +1,95 @@
+//! ETag / conditional-GET support for embedded static files and feeds.
+
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use include_dir::Dir;
+use once_cell::sync::Lazy;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+
+/// Static assets are embedded at compile time and never change at runtime,
+/// so a year-long `max-age` is safe.
+const STATIC_MAX_AGE_SECS: u64 = 60 * 60 * 24 * 365;
+
+/// Feeds are regenerated per-request, so they get a short `max-age` and rely
+/// on the ETag for the common "nothing changed" case.
+const FEED_MAX_AGE_SECS: u64 = 60;
+
+/// A strong ETag is just the hex-encoded SHA-256 of the bytes, quoted per
+/// RFC 9110.
+pub fn etag_for(bytes: &[u8]) -> String {
+    let digest = Sha256::digest(bytes);
+    format!("\"{:x}\"", digest)
+}
+
+/// Precomputed ETags for every embedded static file, keyed by the path used
+/// in `/static/*path` requests. Computed once, on first access, since the
+/// embedded contents never change.
+static STATIC_ETAGS: Lazy<HashMap<String, String>> = Lazy::new(|| {
+    let mut etags = HashMap::new();
+    collect_etags(&STATIC_DIR, &mut etags);
+    etags
+});
+
+use crate::STATIC_DIR;
+
+fn collect_etags(dir: &Dir<'_>, etags: &mut HashMap<String, String>) {
+    for file in dir.files() {
+        let path = file.path().to_string_lossy().to_string();
+        etags.insert(path, etag_for(file.contents()));
+    }
+    for sub_dir in dir.dirs() {
+        collect_etags(sub_dir, etags);
+    }
+}
+
+pub fn static_etag(path: &str) -> Option<&'static str> {
+    STATIC_ETAGS.get(path).map(String::as_str)
+}
+
+fn if_none_match_matches(headers: &HeaderMap, etag: &str) -> bool {
+    headers
+        .get(axum::http::header::IF_NONE_MATCH)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.split(',').any(|candidate| candidate.trim() == etag))
+        .unwrap_or(false)
+}
+
+/// Builds the response for a cacheable body, honoring `If-None-Match` by
+/// returning a bodyless `304` when the client's cached copy is still valid.
+pub fn conditional_response(
+    headers: &HeaderMap,
+    etag: &str,
+    max_age_secs: u64,
+    content_type: &'static str,
+    body: Vec<u8>,
+) -> Response {
+    if if_none_match_matches(headers, etag) {
+        return (
+            StatusCode::NOT_MODIFIED,
+            [
+                ("etag", etag.to_string()),
+                ("cache-control", format!("public, max-age={}", max_age_secs)),
+            ],
+        )
+            .into_response();
+    }
+
+    (
+        [
+            ("content-type", content_type.to_string()),
+            ("etag", etag.to_string()),
+            ("cache-control", format!("public, max-age={}", max_age_secs)),
+        ],
+        body,
+    )
+        .into_response()
+}
+
+pub fn static_max_age() -> u64 {
+    STATIC_MAX_AGE_SECS
+}
+
+pub fn feed_max_age() -> u64 {
+    FEED_MAX_AGE_SECS
+}
@@ -0,0 +1,110 @@
+//! Atom/RSS feed generation for the blog.
+//!
+//! Reuses the same `BlogPostMeta` list that `/blog` renders so the feed and
+//! the HTML index never drift apart.
+
+use crate::BlogPostMeta;
+use atom_syndication::{
+    Entry, EntryBuilder, Feed, FeedBuilder, LinkBuilder, Person, PersonBuilder,
+};
+use chrono::{DateTime, Utc};
+use rss::{Channel, ChannelBuilder, Item, ItemBuilder};
+
+/// Feeds only ever include the most recent posts.
+const MAX_FEED_ENTRIES: usize = 20;
+
+pub const SITE_URL: &str = "https://jambonne.dev";
+const SITE_TITLE: &str = "Jambonne";
+const SITE_AUTHOR: &str = "Jambonne";
+
+fn author() -> Person {
+    PersonBuilder::default().name(SITE_AUTHOR).build()
+}
+
+fn recent_posts(posts: &[BlogPostMeta]) -> Vec<&BlogPostMeta> {
+    let mut sorted: Vec<&BlogPostMeta> = posts.iter().collect();
+    sorted.sort_by(|a, b| b.date.cmp(&a.date));
+    sorted.truncate(MAX_FEED_ENTRIES);
+    sorted
+}
+
+fn entry_id(slug: &str) -> String {
+    format!("{}/blog/{}", SITE_URL, slug)
+}
+
+/// The short summary shown in a feed entry, falling back to the title when
+/// a post has no front-matter `description`.
+fn post_summary(post: &BlogPostMeta) -> String {
+    post.description
+        .clone()
+        .unwrap_or_else(|| post.title.clone())
+}
+
+/// Renders the Atom feed for the most recent posts.
+pub fn render_atom(posts: &[BlogPostMeta]) -> String {
+    let entries: Vec<Entry> = recent_posts(posts)
+        .into_iter()
+        .map(|post| {
+            let id = entry_id(&post.slug);
+            let updated: DateTime<Utc> = post.date;
+            EntryBuilder::default()
+                .id(id.clone())
+                .title(post.title.clone())
+                .updated(updated.into())
+                .authors(vec![author()])
+                .link(LinkBuilder::default().href(id).rel("alternate").build())
+                .summary(Some(post_summary(post).into()))
+                .build()
+        })
+        .collect();
+
+    // Deterministic, not `Utc::now()`: the feed body must be a pure function
+    // of the post list so its ETag is stable across requests (chunk0-2).
+    let updated = recent_posts(posts)
+        .first()
+        .map(|post| post.date)
+        .unwrap_or(DateTime::<Utc>::MIN_UTC);
+
+    let self_link = LinkBuilder::default()
+        .href(format!("{}/feed.xml", SITE_URL))
+        .rel("self")
+        .mime_type(Some("application/atom+xml".to_string()))
+        .build();
+
+    let feed: Feed = FeedBuilder::default()
+        .title(SITE_TITLE)
+        .id(SITE_URL)
+        .updated(updated.into())
+        .authors(vec![author()])
+        .links(vec![self_link])
+        .entries(entries)
+        .build();
+
+    feed.to_string()
+}
+
+/// Renders the RSS 2.0 feed for the most recent posts.
+pub fn render_rss(posts: &[BlogPostMeta]) -> String {
+    let items: Vec<Item> = recent_posts(posts)
+        .into_iter()
+        .map(|post| {
+            let link = entry_id(&post.slug);
+            ItemBuilder::default()
+                .title(Some(post.title.clone()))
+                .link(Some(link.clone()))
+                .guid(Some(rss::GuidBuilder::default().value(link).build()))
+                .pub_date(Some(post.date.to_rfc2822()))
+                .description(Some(post_summary(post)))
+                .build()
+        })
+        .collect();
+
+    let channel: Channel = ChannelBuilder::default()
+        .title(SITE_TITLE)
+        .link(SITE_URL)
+        .description(format!("Recent posts from {}", SITE_TITLE))
+        .items(items)
+        .build();
+
+    channel.to_string()
+}
@@ -0,0 +1,73 @@
+//! YAML front matter parsing for blog posts.
+//!
+//! Posts may start with a `---`-delimited YAML block; content without one
+//! falls back to the legacy "first line is the title" convention so
+//! existing posts keep working untouched.
+
+use fronma::parser::parse;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, Default, Clone)]
+pub struct PostMeta {
+    pub title: Option<String>,
+    #[serde(default)]
+    pub date: Option<String>,
+    #[serde(default, alias = "keywords")]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub draft: bool,
+}
+
+pub struct ParsedPost {
+    pub meta: PostMeta,
+    pub body: String,
+}
+
+/// Parses a post's raw markdown, extracting front matter if present and
+/// falling back to the legacy heading-based title otherwise.
+///
+/// A post that opens with `---` is committed to the front-matter path: if
+/// the YAML fails to parse, we don't fall through to the legacy path, since
+/// that would treat the literal `---` delimiter line as the heading.
+pub fn parse_post(raw: &str) -> ParsedPost {
+    if raw.trim_start().starts_with("---") {
+        return match parse::<PostMeta>(raw) {
+            Ok(parsed) => ParsedPost {
+                meta: parsed.headers,
+                body: parsed.body.to_string(),
+            },
+            Err(e) => {
+                eprintln!("Failed to parse front matter: {}", e);
+                ParsedPost {
+                    meta: PostMeta::default(),
+                    body: raw.to_string(),
+                }
+            }
+        };
+    }
+
+    let mut lines = raw.lines();
+    let heading_title = lines
+        .next()
+        .unwrap_or("Untitled")
+        .trim_start_matches('#')
+        .trim()
+        .to_string();
+    let body = lines.collect::<Vec<_>>().join("\n");
+
+    ParsedPost {
+        meta: PostMeta {
+            title: Some(heading_title),
+            ..Default::default()
+        },
+        body,
+    }
+}
+
+impl PostMeta {
+    pub fn title_or_untitled(&self) -> String {
+        self.title.clone().unwrap_or_else(|| "Untitled".to_string())
+    }
+}
@@ -1,17 +1,34 @@
 use askama::Template;
 use askama_axum::IntoResponse;
 use axum::response::Response;
-use axum::{extract::Path, routing::get, Router};
-use chrono::Utc;
+use axum::{
+    extract::{Path, Query, State},
+    routing::get,
+    Router,
+};
+use chrono::{DateTime, Utc};
 use include_dir::{include_dir, Dir};
 use once_cell::sync::Lazy;
-use pulldown_cmark::{html, Options, Parser};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::net::SocketAddr;
 use std::path::Path as FsPath;
 use tokio::net::TcpListener;
 
+mod caching;
+mod feed;
+mod frontmatter;
+mod mindmap;
+mod post_cache;
+mod reading_time;
+mod webmention;
+
+/// Shared application state, handed to every handler via `Router::with_state`.
+#[derive(Clone, Default)]
+struct AppState {
+    post_cache: post_cache::PostCache,
+}
+
 pub mod filters {
     use askama::Result as AskamaResult;
     use serde::Serialize;
@@ -37,6 +54,12 @@ struct IndexTemplate;
 #[template(path = "blog.html")]
 struct BlogTemplate {
     posts: Vec<BlogPostMeta>,
+    /// Every tag used by at least one post, sorted alphabetically.
+    tags: Vec<String>,
+    /// The tag the listing is currently filtered to, if any.
+    active_tag: Option<String>,
+    sort: String,
+    order: String,
 }
 
 #[derive(Template)]
@@ -48,6 +71,8 @@ struct AboutTemplate;
 struct ArticleTemplate {
     title: String,
     content: String,
+    read_minutes: u32,
+    mentions: Vec<webmention::Webmention>,
 }
 
 // Mind map data structures
@@ -129,6 +154,20 @@ struct ReadingTemplate {
 struct BlogPostMeta {
     slug: String,
     title: String,
+    date: DateTime<Utc>,
+    tags: Vec<String>,
+    word_count: usize,
+    description: Option<String>,
+}
+
+/// Parses a front-matter `date: YYYY-MM-DD` value, treating it as midnight
+/// UTC. Returns `None` on anything that doesn't parse so callers can fall
+/// back to the file's mtime.
+fn parse_front_matter_date(raw: &str) -> Option<DateTime<Utc>> {
+    chrono::NaiveDate::parse_from_str(raw, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|naive| naive.and_utc())
 }
 
 fn is_valid_slug(slug: &str) -> bool {
@@ -144,7 +183,13 @@ async fn landing() -> impl axum::response::IntoResponse {
     IndexTemplate
 }
 
-async fn blog() -> impl axum::response::IntoResponse {
+/// Scans the posts directory for markdown files and their metadata.
+///
+/// Shared by the `/blog` listing and the feed routes so they never disagree
+/// about which posts exist. Each post's parsed front matter and rendered
+/// body are served from `state.post_cache`, which only re-reads a file when
+/// its mtime changes.
+fn discover_posts(state: &AppState) -> Vec<BlogPostMeta> {
     let mut posts = Vec::new();
 
     // Try different possible locations for the posts directory
@@ -157,15 +202,28 @@ async fn blog() -> impl axum::response::IntoResponse {
                     if ext == "md" {
                         let filename = entry.file_name().to_string_lossy().to_string();
                         let slug = filename.trim_end_matches(".md").to_string();
-                        let content = fs::read_to_string(entry.path()).unwrap_or_default();
-                        let title = content
-                            .lines()
-                            .next()
-                            .unwrap_or("Untitled")
-                            .trim_start_matches('#')
-                            .trim()
-                            .to_string();
-                        posts.push(BlogPostMeta { slug, title });
+                        let Some(cached) = state.post_cache.get_or_render(&slug, &entry.path())
+                        else {
+                            continue;
+                        };
+                        if cached.meta.draft {
+                            continue;
+                        }
+                        let title = cached.meta.title_or_untitled();
+                        let date = cached
+                            .meta
+                            .date
+                            .as_deref()
+                            .and_then(parse_front_matter_date)
+                            .unwrap_or_else(|| DateTime::<Utc>::from(cached.mtime));
+                        posts.push(BlogPostMeta {
+                            slug,
+                            title,
+                            date,
+                            tags: cached.meta.tags.clone(),
+                            word_count: cached.word_count,
+                            description: cached.meta.description.clone(),
+                        });
                     }
                 }
             }
@@ -173,11 +231,125 @@ async fn blog() -> impl axum::response::IntoResponse {
         }
     }
 
-    posts.sort_by(|a, b| b.slug.cmp(&a.slug));
-    BlogTemplate { posts }
+    posts.sort_by(|a, b| b.date.cmp(&a.date));
+    posts
+}
+
+#[derive(Deserialize)]
+struct BlogQuery {
+    sort: Option<String>,
+    order: Option<String>,
+}
+
+/// Sorts posts in place. `sort` is `"date"` (default) or `"title"`; `order`
+/// is `"asc"` or `"desc"`, defaulting to the natural order for each field
+/// (newest first for dates, A-to-Z for titles).
+fn sort_posts(posts: &mut [BlogPostMeta], sort: &str, order: &str) {
+    match sort {
+        "title" => {
+            posts.sort_by(|a, b| a.title.to_lowercase().cmp(&b.title.to_lowercase()));
+            if order == "desc" {
+                posts.reverse();
+            }
+        }
+        _ => {
+            posts.sort_by(|a, b| b.date.cmp(&a.date));
+            if order == "asc" {
+                posts.reverse();
+            }
+        }
+    }
+}
+
+/// Every tag used by at least one post, sorted alphabetically.
+fn all_tags(posts: &[BlogPostMeta]) -> Vec<String> {
+    let mut tags: Vec<String> = posts
+        .iter()
+        .flat_map(|post| post.tags.iter().cloned())
+        .collect();
+    tags.sort();
+    tags.dedup();
+    tags
+}
+
+/// The default `order` for a given `sort` field, matching `sort_posts`'s
+/// natural order (newest first for dates, A-to-Z for titles).
+fn default_order(sort: &str) -> &'static str {
+    match sort {
+        "title" => "asc",
+        _ => "desc",
+    }
+}
+
+fn render_blog(
+    mut posts: Vec<BlogPostMeta>,
+    tags: Vec<String>,
+    active_tag: Option<String>,
+    query: BlogQuery,
+) -> BlogTemplate {
+    let sort = query.sort.unwrap_or_else(|| "date".to_string());
+    let order = query
+        .order
+        .unwrap_or_else(|| default_order(&sort).to_string());
+    sort_posts(&mut posts, &sort, &order);
+
+    BlogTemplate {
+        posts,
+        tags,
+        active_tag,
+        sort,
+        order,
+    }
+}
+
+async fn blog(
+    State(state): State<AppState>,
+    Query(query): Query<BlogQuery>,
+) -> impl axum::response::IntoResponse {
+    let posts = discover_posts(&state);
+    let tags = all_tags(&posts);
+    render_blog(posts, tags, None, query)
 }
 
-async fn blog_post(Path(slug): Path<String>) -> Response {
+async fn blog_by_tag(
+    State(state): State<AppState>,
+    Path(tag): Path<String>,
+    Query(query): Query<BlogQuery>,
+) -> impl axum::response::IntoResponse {
+    let all_posts = discover_posts(&state);
+    let tags = all_tags(&all_posts);
+    let posts: Vec<BlogPostMeta> = all_posts
+        .into_iter()
+        .filter(|post| post.tags.iter().any(|t| t == &tag))
+        .collect();
+    render_blog(posts, tags, Some(tag), query)
+}
+
+async fn atom_feed(State(state): State<AppState>, headers: axum::http::HeaderMap) -> Response {
+    let body = feed::render_atom(&discover_posts(&state));
+    let etag = caching::etag_for(body.as_bytes());
+    caching::conditional_response(
+        &headers,
+        &etag,
+        caching::feed_max_age(),
+        "application/atom+xml; charset=utf-8",
+        body.into_bytes(),
+    )
+}
+
+async fn rss_feed(State(state): State<AppState>, headers: axum::http::HeaderMap) -> Response {
+    let body = feed::render_rss(&discover_posts(&state));
+    let etag = caching::etag_for(body.as_bytes());
+    caching::conditional_response(
+        &headers,
+        &etag,
+        caching::feed_max_age(),
+        "application/rss+xml; charset=utf-8",
+        body.into_bytes(),
+    )
+}
+
+async fn blog_post(State(state): State<AppState>, Path(slug): Path<String>) -> Response {
     if !is_valid_slug(&slug) {
         return axum::http::StatusCode::BAD_REQUEST.into_response();
     }
@@ -198,26 +370,41 @@ async fn blog_post(Path(slug): Path<String>) -> Response {
         return axum::http::StatusCode::NOT_FOUND.into_response();
     }
 
-    let markdown = fs::read_to_string(&path).unwrap_or_default();
-    let mut lines = markdown.lines();
-    let title = lines
-        .next()
-        .unwrap_or("Untitled")
-        .trim_start_matches('#')
-        .trim();
-    let content_md: String = lines.collect::<Vec<_>>().join("\n");
-
-    let mut html_output = String::new();
-    let parser = Parser::new_ext(&content_md, Options::all());
-    html::push_html(&mut html_output, parser);
+    let Some(cached) = state.post_cache.get_or_render(&slug, FsPath::new(&path)) else {
+        return axum::http::StatusCode::NOT_FOUND.into_response();
+    };
+    if cached.meta.draft {
+        return axum::http::StatusCode::NOT_FOUND.into_response();
+    }
 
     ArticleTemplate {
-        title: title.to_string(),
-        content: html_output,
+        title: cached.meta.title_or_untitled(),
+        content: cached.html,
+        read_minutes: cached.read_minutes,
+        mentions: webmention::mentions_for(&slug),
     }
     .into_response()
 }
 
+#[derive(Deserialize)]
+struct WebmentionForm {
+    source: String,
+    target: String,
+}
+
+/// Accepts a webmention, validates it synchronously, then verifies and
+/// persists it off the request path so the sender gets a fast `202`.
+async fn webmention_handler(
+    axum::extract::Form(form): axum::extract::Form<WebmentionForm>,
+) -> Response {
+    if webmention::target_slug(&form.target).is_none() {
+        return axum::http::StatusCode::BAD_REQUEST.into_response();
+    }
+
+    tokio::spawn(webmention::verify_and_store(form.source, form.target));
+    axum::http::StatusCode::ACCEPTED.into_response()
+}
+
 async fn about() -> impl axum::response::IntoResponse {
     AboutTemplate
 }
@@ -233,16 +420,44 @@ fn build_router() -> Router {
         )
         .route(
             "/blog",
-            get(|| async {
+            get(|state, query| async move {
                 println!("Handling blog page request");
-                blog().await
+                blog(state, query).await
+            }),
+        )
+        .route(
+            "/blog/tag/:tag",
+            get(|state, path, query| async move {
+                println!("Handling blog tag page request: {:?}", path);
+                blog_by_tag(state, path, query).await
             }),
         )
         .route(
             "/blog/:slug",
-            get(|path| async move {
+            get(|state, path| async move {
                 println!("Handling blog post request: {:?}", path);
-                blog_post(path).await
+                blog_post(state, path).await
+            }),
+        )
+        .route(
+            "/feed.xml",
+            get(|state, headers| async move {
+                println!("Handling atom feed request");
+                atom_feed(state, headers).await
+            }),
+        )
+        .route(
+            "/rss.xml",
+            get(|state, headers| async move {
+                println!("Handling rss feed request");
+                rss_feed(state, headers).await
+            }),
+        )
+        .route(
+            "/webmention",
+            axum::routing::post(|form| async move {
+                println!("Handling webmention request");
+                webmention_handler(form).await
             }),
         )
         .route(
@@ -261,11 +476,12 @@ fn build_router() -> Router {
         )
         .route(
             "/static/*path",
-            get(|path| async move {
+            get(|path, headers| async move {
                 println!("Handling static file request: {:?}", path);
-                static_handler(path).await
+                static_handler(path, headers).await
             }),
         )
+        .with_state(AppState::default())
 }
 
 // Reading list handler
@@ -280,26 +496,53 @@ async fn reading() -> impl IntoResponse {
         .into_response();
     }
 
+    let titles: Vec<String> = items.iter().map(|item| item.title.clone()).collect();
+    let graph = mindmap::build_graph(&titles);
+
     // Convert ReadingListItem to MindMapNode for template compatibility
     let nodes: Vec<MindMapNode> = items
         .iter()
         .enumerate()
-        .map(|(i, item)| MindMapNode {
-            id: i.to_string(),
-            title: item.title.clone(),
-            url: item.url.clone(),
-            cluster: 0,
-            position: Position { x: 0.0, y: 0.0 },
-            keywords: vec![],
-            content_preview: "".to_string(),
+        .map(|(i, item)| {
+            let (x, y) = graph.positions[i];
+            MindMapNode {
+                id: i.to_string(),
+                title: item.title.clone(),
+                url: item.url.clone(),
+                cluster: graph.clusters[i] as i32,
+                position: Position { x, y },
+                keywords: graph.keywords[i].clone(),
+                content_preview: "".to_string(),
+            }
+        })
+        .collect();
+
+    let edges: Vec<MindMapEdge> = graph
+        .edges
+        .iter()
+        .map(|&(source, target, weight)| MindMapEdge {
+            source: source.to_string(),
+            target: target.to_string(),
+            weight,
         })
         .collect();
 
+    let clusters: Vec<MindMapCluster> =
+        mindmap::summarize_clusters(&graph.clusters, &graph.keywords)
+            .into_iter()
+            .map(|summary| MindMapCluster {
+                id: summary.id as i32,
+                name: summary.name,
+                keywords: summary.keywords,
+                articles: summary.articles.into_iter().map(|i| i as i32).collect(),
+            })
+            .collect();
+
     let reading_data = ReadingData {
         id: "reading-list".to_string(),
         nodes,
-        edges: vec![],
-        clusters: vec![],
+        edges,
+        clusters,
         metadata: serde_json::json!({}),
         created_at: Utc::now().to_rfc3339(),
     };
@@ -312,7 +555,7 @@ async fn reading() -> impl IntoResponse {
 }
 
 // Custom static file handler that serves from embedded files
-async fn static_handler(Path(path): Path<String>) -> Response {
+async fn static_handler(Path(path): Path<String>, headers: axum::http::HeaderMap) -> Response {
     if let Some(file) = STATIC_DIR.get_file(&path) {
         let content_type = match path.split('.').next_back() {
             Some("css") => "text/css",
@@ -326,8 +569,24 @@ async fn static_handler(Path(path): Path<String>) -> Response {
             Some("pdf") => "application/pdf",
             _ => "text/plain",
         };
-        let headers = [("content-type", content_type)];
-        return (headers, file.contents()).into_response();
+        // Shouldn't happen: every embedded file is hashed into STATIC_ETAGS
+        // on first access. Fall back to hashing it here rather than serving a
+        // bogus empty ETag.
+        let fallback_etag;
+        let etag = match caching::static_etag(&path) {
+            Some(etag) => etag,
+            None => {
+                fallback_etag = caching::etag_for(file.contents());
+                &fallback_etag
+            }
+        };
+        return caching::conditional_response(
+            &headers,
+            etag,
+            caching::static_max_age(),
+            content_type,
+            file.contents().to_vec(),
+        );
     }
     axum::http::StatusCode::NOT_FOUND.into_response()
 }
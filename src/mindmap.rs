@@ -0,0 +1,225 @@
+//! Keyword extraction, Jaccard-similarity edges, connected-component
+//! clustering, and Fruchterman-Reingold layout for the reading mind map.
+
+use rand::Rng;
+use std::collections::{HashMap, HashSet};
+
+const STOPWORDS: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "be", "but", "by", "for", "from", "has", "he", "in", "is",
+    "it", "its", "of", "on", "or", "that", "the", "to", "was", "were", "will", "with", "how",
+    "why", "what", "your", "you", "this", "their", "about", "into",
+];
+
+const MIN_KEYWORD_LEN: usize = 3;
+/// Edges below this Jaccard similarity are treated as noise and dropped.
+const SIMILARITY_THRESHOLD: f64 = 0.15;
+
+const CANVAS_WIDTH: f64 = 1000.0;
+const CANVAS_HEIGHT: f64 = 800.0;
+const LAYOUT_ITERATIONS: usize = 200;
+
+pub struct ClusterSummary {
+    pub id: usize,
+    pub name: String,
+    pub keywords: Vec<String>,
+    pub articles: Vec<usize>,
+}
+
+pub struct Graph {
+    pub keywords: Vec<Vec<String>>,
+    pub edges: Vec<(usize, usize, f64)>,
+    pub clusters: Vec<usize>,
+    pub positions: Vec<(f64, f64)>,
+}
+
+/// Lowercases, strips punctuation, and drops stopwords/short tokens, in
+/// first-seen order with duplicates removed.
+pub fn extract_keywords(text: &str) -> Vec<String> {
+    let mut seen = HashSet::new();
+    text.split_whitespace()
+        .map(|word| {
+            word.chars()
+                .filter(|c| c.is_alphanumeric())
+                .collect::<String>()
+                .to_lowercase()
+        })
+        .filter(|word| word.len() >= MIN_KEYWORD_LEN && !STOPWORDS.contains(&word.as_str()))
+        .filter(|word| seen.insert(word.clone()))
+        .collect()
+}
+
+fn jaccard(a: &HashSet<&String>, b: &HashSet<&String>) -> f64 {
+    let intersection = a.intersection(b).count();
+    if intersection == 0 {
+        return 0.0;
+    }
+    let union = a.union(b).count();
+    intersection as f64 / union as f64
+}
+
+fn build_edges(keyword_sets: &[Vec<String>]) -> Vec<(usize, usize, f64)> {
+    let sets: Vec<HashSet<&String>> = keyword_sets.iter().map(|kw| kw.iter().collect()).collect();
+    let mut edges = Vec::new();
+    for i in 0..sets.len() {
+        for j in (i + 1)..sets.len() {
+            let weight = jaccard(&sets[i], &sets[j]);
+            if weight >= SIMILARITY_THRESHOLD {
+                edges.push((i, j, weight));
+            }
+        }
+    }
+    edges
+}
+
+/// Groups nodes into connected components via union-find over the edge set.
+fn cluster_nodes(n: usize, edges: &[(usize, usize, f64)]) -> Vec<usize> {
+    let mut parent: Vec<usize> = (0..n).collect();
+
+    fn find(parent: &mut [usize], x: usize) -> usize {
+        if parent[x] != x {
+            parent[x] = find(parent, parent[x]);
+        }
+        parent[x]
+    }
+
+    for &(a, b, _) in edges {
+        let (ra, rb) = (find(&mut parent, a), find(&mut parent, b));
+        if ra != rb {
+            parent[ra] = rb;
+        }
+    }
+
+    let mut cluster_ids: HashMap<usize, usize> = HashMap::new();
+    (0..n)
+        .map(|i| {
+            let root = find(&mut parent, i);
+            let next_id = cluster_ids.len();
+            *cluster_ids.entry(root).or_insert(next_id)
+        })
+        .collect()
+}
+
+/// Fruchterman-Reingold force-directed layout, positions clamped to the
+/// canvas bounds.
+fn force_directed_layout(n: usize, edges: &[(usize, usize, f64)]) -> Vec<(f64, f64)> {
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let area = CANVAS_WIDTH * CANVAS_HEIGHT;
+    let k = (area / n as f64).sqrt();
+
+    let mut rng = rand::thread_rng();
+    let mut positions: Vec<(f64, f64)> = (0..n)
+        .map(|_| {
+            (
+                rng.gen_range(0.0..CANVAS_WIDTH),
+                rng.gen_range(0.0..CANVAS_HEIGHT),
+            )
+        })
+        .collect();
+
+    let mut temperature = CANVAS_WIDTH / 10.0;
+    let cooling = temperature / LAYOUT_ITERATIONS as f64;
+
+    for _ in 0..LAYOUT_ITERATIONS {
+        let mut displacement = vec![(0.0_f64, 0.0_f64); n];
+
+        // Repulsive force between every pair of nodes.
+        for i in 0..n {
+            for j in 0..n {
+                if i == j {
+                    continue;
+                }
+                let dx = positions[i].0 - positions[j].0;
+                let dy = positions[i].1 - positions[j].1;
+                let dist = (dx * dx + dy * dy).sqrt().max(0.01);
+                let force = (k * k) / dist;
+                displacement[i].0 += (dx / dist) * force;
+                displacement[i].1 += (dy / dist) * force;
+            }
+        }
+
+        // Attractive force along edges, scaled by similarity weight.
+        for &(a, b, weight) in edges {
+            let dx = positions[a].0 - positions[b].0;
+            let dy = positions[a].1 - positions[b].1;
+            let dist = (dx * dx + dy * dy).sqrt().max(0.01);
+            let force = (dist * dist) / k * weight;
+            let (fx, fy) = ((dx / dist) * force, (dy / dist) * force);
+            displacement[a].0 -= fx;
+            displacement[a].1 -= fy;
+            displacement[b].0 += fx;
+            displacement[b].1 += fy;
+        }
+
+        for (i, position) in positions.iter_mut().enumerate() {
+            let (dx, dy) = displacement[i];
+            let dist = (dx * dx + dy * dy).sqrt().max(0.01);
+            let capped = dist.min(temperature);
+            position.0 = (position.0 + (dx / dist) * capped).clamp(0.0, CANVAS_WIDTH);
+            position.1 = (position.1 + (dy / dist) * capped).clamp(0.0, CANVAS_HEIGHT);
+        }
+
+        temperature -= cooling;
+    }
+
+    positions
+}
+
+/// Builds the full graph (keywords, edges, clusters, positions) for a set
+/// of reading-list item titles.
+pub fn build_graph(titles: &[String]) -> Graph {
+    let keywords: Vec<Vec<String>> = titles.iter().map(|title| extract_keywords(title)).collect();
+    let edges = build_edges(&keywords);
+    let clusters = cluster_nodes(titles.len(), &edges);
+    let positions = force_directed_layout(titles.len(), &edges);
+
+    Graph {
+        keywords,
+        edges,
+        clusters,
+        positions,
+    }
+}
+
+/// Summarizes each cluster by its member articles and most common keywords,
+/// naming it after the single most frequent keyword.
+pub fn summarize_clusters(clusters: &[usize], keywords: &[Vec<String>]) -> Vec<ClusterSummary> {
+    let Some(&max_cluster) = clusters.iter().max() else {
+        return Vec::new();
+    };
+
+    (0..=max_cluster)
+        .map(|cluster_id| {
+            let articles: Vec<usize> = clusters
+                .iter()
+                .enumerate()
+                .filter(|(_, &c)| c == cluster_id)
+                .map(|(i, _)| i)
+                .collect();
+
+            let mut keyword_counts: HashMap<&String, usize> = HashMap::new();
+            for &article in &articles {
+                for keyword in &keywords[article] {
+                    *keyword_counts.entry(keyword).or_insert(0) += 1;
+                }
+            }
+            let mut top_keywords: Vec<&String> = keyword_counts.keys().copied().collect();
+            top_keywords.sort_by_key(|keyword| std::cmp::Reverse(keyword_counts[keyword]));
+            top_keywords.truncate(5);
+
+            let name = top_keywords
+                .first()
+                .map(|keyword| keyword.to_string())
+                .unwrap_or_else(|| format!("Cluster {}", cluster_id));
+
+            ClusterSummary {
+                id: cluster_id,
+                name,
+                keywords: top_keywords.into_iter().cloned().collect(),
+                articles,
+            }
+        })
+        .collect()
+}
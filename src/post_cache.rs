@@ -0,0 +1,64 @@
+//! Concurrent cache of parsed front matter and rendered HTML for blog posts.
+//!
+//! Keyed by slug and invalidated by the source file's mtime, so `/blog` and
+//! `/blog/:slug` only re-read and re-render a post when it actually changed
+//! on disk.
+
+use crate::frontmatter::{self, PostMeta};
+use crate::reading_time;
+use pulldown_cmark::{html, Options, Parser};
+use scc::HashMap as ConcurrentHashMap;
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::SystemTime;
+
+#[derive(Clone)]
+pub struct CachedPost {
+    pub mtime: SystemTime,
+    pub meta: PostMeta,
+    pub html: String,
+    pub word_count: usize,
+    pub read_minutes: u32,
+}
+
+#[derive(Clone, Default)]
+pub struct PostCache {
+    entries: Arc<ConcurrentHashMap<String, CachedPost>>,
+}
+
+impl PostCache {
+    /// Returns the cached render for `slug`, re-parsing and re-rendering
+    /// `path` only when its mtime has changed since it was last cached.
+    /// Returns `None` if the file can't be stat'd or read.
+    pub fn get_or_render(&self, slug: &str, path: &Path) -> Option<CachedPost> {
+        let mtime = fs::metadata(path).and_then(|m| m.modified()).ok()?;
+
+        if let Some(entry) = self.entries.get(slug) {
+            if entry.get().mtime == mtime {
+                return Some(entry.get().clone());
+            }
+        }
+
+        let raw = fs::read_to_string(path).ok()?;
+        let parsed = frontmatter::parse_post(&raw);
+        let word_count = reading_time::word_count(&parsed.body);
+        let read_minutes = reading_time::estimate_minutes(&parsed.body);
+
+        let mut html_output = String::new();
+        let parser = Parser::new_ext(&parsed.body, Options::all());
+        html::push_html(&mut html_output, parser);
+
+        let cached = CachedPost {
+            mtime,
+            meta: parsed.meta,
+            html: html_output,
+            word_count,
+            read_minutes,
+        };
+
+        let _ = self.entries.remove(slug);
+        let _ = self.entries.insert(slug.to_string(), cached.clone());
+        Some(cached)
+    }
+}
@@ -0,0 +1,31 @@
+//! Estimated reading time for article pages.
+
+/// Average adult silent-reading speed.
+const WORDS_PER_MINUTE: f64 = 200.0;
+
+/// Seconds added per image, front-loaded and decreasing toward a floor as
+/// later images get skimmed faster.
+const IMAGE_SECONDS: [u32; 9] = [12, 11, 10, 9, 8, 7, 6, 5, 4];
+const IMAGE_SECONDS_FLOOR: u32 = 3;
+
+/// Counts words by splitting on Unicode whitespace.
+pub fn word_count(body: &str) -> usize {
+    body.split_whitespace().count()
+}
+
+fn image_count(body: &str) -> usize {
+    body.matches("![").count()
+}
+
+fn image_seconds(count: usize) -> u32 {
+    (0..count)
+        .map(|i| *IMAGE_SECONDS.get(i).unwrap_or(&IMAGE_SECONDS_FLOOR))
+        .sum()
+}
+
+/// Estimates reading time in whole minutes, rounded up and floored at 1.
+pub fn estimate_minutes(body: &str) -> u32 {
+    let reading_secs = (word_count(body) as f64 / WORDS_PER_MINUTE) * 60.0;
+    let total_secs = reading_secs + image_seconds(image_count(body)) as f64;
+    ((total_secs / 60.0).ceil() as u32).max(1)
+}
@@ -0,0 +1,197 @@
+//! IndieWeb-style webmention receiving, following the same file-backed JSON
+//! store pattern as the reading list.
+
+use crate::feed::SITE_URL;
+use crate::is_valid_slug;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::net::IpAddr;
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::Duration;
+
+const DEFAULT_WEBMENTIONS_FILE: &str = "static/data/webmentions.json";
+
+/// Source pages are attacker-controlled URLs, so the fetch is bounded on
+/// every axis: how long we'll wait, how much we'll read, and which hosts
+/// we'll talk to at all.
+const SOURCE_FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+const MAX_SOURCE_BODY_BYTES: usize = 1024 * 1024;
+
+#[derive(Debug)]
+struct RejectedSourceUrl(String);
+
+impl fmt::Display for RejectedSourceUrl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "rejected webmention source URL: {}", self.0)
+    }
+}
+
+impl std::error::Error for RejectedSourceUrl {}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Webmention {
+    pub source: String,
+    pub target: String,
+    pub verified_at: String,
+}
+
+fn webmentions_path() -> String {
+    std::env::var("WEBMENTIONS_FILE").unwrap_or_else(|_| DEFAULT_WEBMENTIONS_FILE.to_string())
+}
+
+fn load_webmentions_from_file() -> Result<Vec<Webmention>, Box<dyn std::error::Error>> {
+    let data = fs::read_to_string(webmentions_path())?;
+    Ok(serde_json::from_str(&data)?)
+}
+
+static WEBMENTIONS: Lazy<Mutex<Vec<Webmention>>> = Lazy::new(|| {
+    Mutex::new(load_webmentions_from_file().unwrap_or_else(|e| {
+        eprintln!("Failed to load webmentions: {}", e);
+        Vec::new()
+    }))
+});
+
+fn persist(mentions: &[Webmention]) {
+    let data = match serde_json::to_string_pretty(mentions) {
+        Ok(data) => data,
+        Err(e) => {
+            eprintln!("Failed to serialize webmentions: {}", e);
+            return;
+        }
+    };
+    if let Err(e) = fs::write(webmentions_path(), data) {
+        eprintln!("Failed to persist webmentions: {}", e);
+    }
+}
+
+/// Verified mentions received for a blog post, for rendering beneath its
+/// content.
+pub fn mentions_for(slug: &str) -> Vec<Webmention> {
+    let target = format!("{}/blog/{}", SITE_URL, slug);
+    WEBMENTIONS
+        .lock()
+        .expect("webmentions lock poisoned")
+        .iter()
+        .filter(|mention| mention.target == target)
+        .cloned()
+        .collect()
+}
+
+/// Validates that `target` points at one of our own, existing blog posts
+/// and returns its slug. Rejects off-site URLs and slugs with no matching
+/// post.
+pub fn target_slug(target: &str) -> Option<String> {
+    let prefix = format!("{}/blog/", SITE_URL);
+    let slug = target.strip_prefix(&prefix)?;
+    if slug.is_empty() || slug.contains('/') || !is_valid_slug(slug) {
+        return None;
+    }
+
+    let exists = ["posts", "/app/posts"]
+        .iter()
+        .any(|dir| Path::new(&format!("{}/{}.md", dir, slug)).exists());
+    exists.then(|| slug.to_string())
+}
+
+/// Fetches `source` and checks that it actually links to `target`. Runs off
+/// the request path (spawned by the handler), persisting the mention only
+/// once it's verified.
+pub async fn verify_and_store(source: String, target: String) {
+    match fetch_contains_link(&source, &target).await {
+        Ok(true) => {
+            let mention = Webmention {
+                source,
+                target,
+                verified_at: chrono::Utc::now().to_rfc3339(),
+            };
+            let mut mentions = WEBMENTIONS.lock().expect("webmentions lock poisoned");
+            mentions.push(mention);
+            persist(&mentions);
+        }
+        Ok(false) => {
+            eprintln!("webmention source {} does not link to {}", source, target);
+        }
+        Err(e) => {
+            eprintln!("failed to verify webmention from {}: {}", source, e);
+        }
+    }
+}
+
+fn is_internal_v4(ip: std::net::Ipv4Addr) -> bool {
+    ip.is_loopback() || ip.is_private() || ip.is_link_local() || ip.is_unspecified()
+}
+
+/// True for hosts that resolve (syntactically) to loopback, private, or
+/// link-local addresses, or the bare name `localhost` — the obvious SSRF
+/// targets we can catch without a DNS lookup. Covers IPv4-mapped IPv6
+/// addresses and the IPv6 unique-local (`fc00::/7`) and link-local
+/// (`fe80::/10`) ranges, since `std::net::Ipv6Addr` has no stable helper
+/// for either.
+fn is_internal_host(host: &str) -> bool {
+    if host.eq_ignore_ascii_case("localhost") {
+        return true;
+    }
+    match host.parse::<IpAddr>() {
+        Ok(IpAddr::V4(ip)) => is_internal_v4(ip),
+        Ok(IpAddr::V6(ip)) => {
+            if let Some(mapped) = ip.to_ipv4_mapped() {
+                return is_internal_v4(mapped);
+            }
+            let is_unique_local = (ip.segments()[0] & 0xfe00) == 0xfc00;
+            let is_unicast_link_local = (ip.segments()[0] & 0xffc0) == 0xfe80;
+            ip.is_loopback() || ip.is_unspecified() || is_unique_local || is_unicast_link_local
+        }
+        Err(_) => false,
+    }
+}
+
+fn validate_source_url(source: &str) -> Result<(), RejectedSourceUrl> {
+    let url = reqwest::Url::parse(source).map_err(|_| RejectedSourceUrl(source.to_string()))?;
+    let scheme_ok = url.scheme() == "http" || url.scheme() == "https";
+    // `host_str` wraps IPv6 literals in brackets; strip them so they parse.
+    let host_ok = url.host_str().is_some_and(|host| {
+        !is_internal_host(host.trim_start_matches('[').trim_end_matches(']'))
+    });
+    if scheme_ok && host_ok {
+        Ok(())
+    } else {
+        Err(RejectedSourceUrl(source.to_string()))
+    }
+}
+
+async fn fetch_contains_link(
+    source: &str,
+    target: &str,
+) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+    validate_source_url(source)?;
+
+    // Redirects are disabled rather than followed: a host that passes
+    // `validate_source_url` could otherwise 302 us to an internal address,
+    // bypassing the check entirely.
+    let client = reqwest::Client::builder()
+        .connect_timeout(SOURCE_FETCH_TIMEOUT)
+        .timeout(SOURCE_FETCH_TIMEOUT)
+        .redirect(reqwest::redirect::Policy::none())
+        .build()?;
+
+    let mut response = client.get(source).send().await?;
+    if response
+        .content_length()
+        .is_some_and(|len| len > MAX_SOURCE_BODY_BYTES as u64)
+    {
+        return Err(Box::new(RejectedSourceUrl(source.to_string())));
+    }
+
+    let mut body = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        body.extend_from_slice(&chunk);
+        if body.len() > MAX_SOURCE_BODY_BYTES {
+            break;
+        }
+    }
+
+    Ok(String::from_utf8_lossy(&body).contains(target))
+}